@@ -46,8 +46,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, Ime, MonitorSelection,
-        VideoModeSelection, Window, WindowMoved, WindowPlugin, WindowPosition,
-        WindowResizeConstraints,
+        VideoModeSelection, Window, WindowCloseBehavior, WindowMoved, WindowPlugin,
+        WindowPosition, WindowResizeConstraints,
     };
 }
 
@@ -122,6 +122,8 @@ impl Plugin for WindowPlugin {
             .add_event::<Ime>()
             .add_event::<WindowFocused>()
             .add_event::<WindowOccluded>()
+            .add_event::<WindowShown>()
+            .add_event::<WindowHidden>()
             .add_event::<WindowScaleFactorChanged>()
             .add_event::<WindowBackendScaleFactorChanged>()
             .add_event::<FileDragAndDrop>()
@@ -155,6 +157,9 @@ impl Plugin for WindowPlugin {
             app.add_systems(Update, close_when_requested);
         }
 
+        // Emit `WindowShown`/`WindowHidden` when `Window::visible` changes.
+        app.add_systems(Update, window_visibility_changed);
+
         // Register event types
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<WindowEvent>()
@@ -169,6 +174,8 @@ impl Plugin for WindowPlugin {
             .register_type::<CursorLeft>()
             .register_type::<WindowFocused>()
             .register_type::<WindowOccluded>()
+            .register_type::<WindowShown>()
+            .register_type::<WindowHidden>()
             .register_type::<WindowScaleFactorChanged>()
             .register_type::<WindowBackendScaleFactorChanged>()
             .register_type::<FileDragAndDrop>()
@@ -181,7 +188,8 @@ impl Plugin for WindowPlugin {
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<Window>()
             .register_type::<PrimaryWindow>()
-            .register_type::<CursorOptions>();
+            .register_type::<CursorOptions>()
+            .register_type::<WindowCloseBehavior>();
     }
 }
 
@@ -0,0 +1,192 @@
+use crate::{
+    PrimaryWindow, Window, WindowCloseBehavior, WindowCloseRequested, WindowHidden, WindowShown,
+};
+
+use bevy_app::AppExit;
+use bevy_ecs::prelude::*;
+use bevy_platform::collections::HashMap;
+
+/// Exit the application when there are no open windows.
+///
+/// This system is added by the [`WindowPlugin`] in the default configuration.
+/// To disable this behavior, set `exit_condition` (on the [`WindowPlugin`]) to
+/// [`ExitCondition::DontExit`].
+/// To directly close the app, send an [`AppExit`] event instead.
+///
+/// [`WindowPlugin`]: crate::WindowPlugin
+/// [`ExitCondition::DontExit`]: crate::ExitCondition::DontExit
+pub fn exit_on_all_closed(mut app_exit_events: EventWriter<AppExit>, windows: Query<&Window>) {
+    if windows.is_empty() {
+        app_exit_events.write(AppExit::Success);
+    }
+}
+
+/// Exit the application when the primary window has been closed.
+///
+/// This system is added by the [`WindowPlugin`].
+///
+/// [`WindowPlugin`]: crate::WindowPlugin
+pub fn exit_on_primary_closed(
+    mut app_exit_events: EventWriter<AppExit>,
+    windows: Query<(), (With<Window>, With<PrimaryWindow>)>,
+) {
+    if windows.is_empty() {
+        app_exit_events.write(AppExit::Success);
+    }
+}
+
+/// Handles [`WindowCloseRequested`] events according to each window's
+/// [`close_behavior`](Window::close_behavior):
+///
+/// - [`WindowCloseBehavior::Close`] despawns the window entity, destroying its surface.
+/// - [`WindowCloseBehavior::Hide`] keeps the window alive and only sets
+///   [`Window::visible`] to `false`, so it can be reshown cheaply.
+/// - [`WindowCloseBehavior::None`] does nothing, leaving the decision to user code.
+///
+/// This system is added by the [`WindowPlugin`] in the default configuration
+/// (`close_when_requested: true`).
+///
+/// [`WindowPlugin`]: crate::WindowPlugin
+pub fn close_when_requested(
+    mut commands: Commands,
+    mut closed: EventReader<WindowCloseRequested>,
+    mut windows: Query<&mut Window>,
+) {
+    for event in closed.read() {
+        let Ok(mut window) = windows.get_mut(event.window) else {
+            continue;
+        };
+
+        match window.close_behavior {
+            WindowCloseBehavior::Close => {
+                commands.entity(event.window).despawn();
+            }
+            WindowCloseBehavior::Hide => {
+                window.visible = false;
+            }
+            WindowCloseBehavior::None => {}
+        }
+    }
+}
+
+/// Emits [`WindowShown`] and [`WindowHidden`] events by diffing each window's
+/// [`Window::visible`] field against its value on the previous frame.
+///
+/// A window is reported each time its visibility differs from the last value seen; a newly
+/// observed window is seeded into the tracking map without emitting an event, so an
+/// already-visible window (such as the primary window on the first frame) does not spuriously
+/// report a `false -> true` transition. Entities no longer present in the query are pruned so the
+/// map does not grow across repeated spawn/despawn cycles.
+///
+/// This system is added by the [`WindowPlugin`].
+///
+/// [`WindowPlugin`]: crate::WindowPlugin
+pub fn window_visibility_changed(
+    mut was_visible: Local<HashMap<Entity, bool>>,
+    windows: Query<(Entity, &Window)>,
+    mut shown: EventWriter<WindowShown>,
+    mut hidden: EventWriter<WindowHidden>,
+) {
+    for (entity, window) in &windows {
+        match was_visible.insert(entity, window.visible) {
+            // Visibility unchanged since last frame.
+            Some(previous) if previous == window.visible => {}
+            // First observation of this window: seed the map without emitting, so an
+            // already-visible window does not report a spurious `WindowShown`.
+            None => {}
+            Some(_) => {
+                if window.visible {
+                    shown.write(WindowShown { window: entity });
+                } else {
+                    hidden.write(WindowHidden { window: entity });
+                }
+            }
+        }
+    }
+
+    // Drop entries for windows that no longer exist so the map stays bounded.
+    was_visible.retain(|entity, _| windows.contains(*entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::event::Events;
+    use bevy_ecs::system::SystemId;
+
+    fn setup() -> (World, SystemId) {
+        let mut world = World::new();
+        world.init_resource::<Events<WindowShown>>();
+        world.init_resource::<Events<WindowHidden>>();
+        let id = world.register_system(window_visibility_changed);
+        (world, id)
+    }
+
+    fn drain_shown(world: &mut World) -> usize {
+        world.resource_mut::<Events<WindowShown>>().drain().count()
+    }
+
+    fn drain_hidden(world: &mut World) -> usize {
+        world.resource_mut::<Events<WindowHidden>>().drain().count()
+    }
+
+    #[test]
+    fn first_observation_of_visible_window_does_not_emit() {
+        let (mut world, id) = setup();
+        world.spawn(Window::default());
+
+        world.run_system(id).unwrap();
+
+        assert_eq!(drain_shown(&mut world), 0);
+        assert_eq!(drain_hidden(&mut world), 0);
+    }
+
+    #[test]
+    fn toggling_visibility_emits_exactly_once_per_transition() {
+        let (mut world, id) = setup();
+        let entity = world.spawn(Window::default()).id();
+
+        // First observation seeds the map without emitting.
+        world.run_system(id).unwrap();
+        assert_eq!(drain_shown(&mut world), 0);
+        assert_eq!(drain_hidden(&mut world), 0);
+
+        // `true -> false` emits a single `WindowHidden`.
+        world.get_mut::<Window>(entity).unwrap().visible = false;
+        world.run_system(id).unwrap();
+        assert_eq!(drain_hidden(&mut world), 1);
+        assert_eq!(drain_shown(&mut world), 0);
+
+        // No change since last frame emits nothing.
+        world.run_system(id).unwrap();
+        assert_eq!(drain_hidden(&mut world), 0);
+        assert_eq!(drain_shown(&mut world), 0);
+
+        // `false -> true` emits a single `WindowShown`.
+        world.get_mut::<Window>(entity).unwrap().visible = true;
+        world.run_system(id).unwrap();
+        assert_eq!(drain_shown(&mut world), 1);
+        assert_eq!(drain_hidden(&mut world), 0);
+    }
+
+    #[test]
+    fn despawned_windows_are_pruned() {
+        let (mut world, id) = setup();
+        let entity = world.spawn(Window::default()).id();
+        world.run_system(id).unwrap();
+        let _ = (drain_shown(&mut world), drain_hidden(&mut world));
+
+        // Despawning and re-running exercises the prune path without emitting.
+        // A window spawned afterwards is a new entity and is treated as a
+        // first observation (no emit).
+        world.despawn(entity);
+        world.run_system(id).unwrap();
+        assert_eq!(drain_shown(&mut world), 0);
+        assert_eq!(drain_hidden(&mut world), 0);
+
+        world.spawn(Window::default());
+        world.run_system(id).unwrap();
+        assert_eq!(drain_shown(&mut world), 0);
+        assert_eq!(drain_hidden(&mut world), 0);
+    }
+}